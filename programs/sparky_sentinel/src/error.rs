@@ -0,0 +1,54 @@
+//! Error types for the Sentinel program.
+
+use num_derive::FromPrimitive;
+use solana_program::{
+    decode_error::DecodeError,
+    msg,
+    program_error::{PrintProgramError, ProgramError},
+};
+use thiserror::Error;
+
+/// Errors that may be returned by the Sentinel program.
+#[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
+pub enum SentinelError {
+    /// The sentinel account has not been initialized yet.
+    #[error("Sentinel account not initialized")]
+    NotInitialized,
+
+    /// The sentinel account has already been initialized.
+    #[error("Sentinel account already initialized")]
+    AlreadyInitialized,
+
+    /// The signer is not the sentinel's authority.
+    #[error("Account is not authorized to perform this action")]
+    Unauthorized,
+
+    /// The instruction data could not be deserialized.
+    #[error("Instruction data could not be unpacked")]
+    InvalidInstructionData,
+
+    /// The account is not owned by this program.
+    #[error("Account is not owned by the sentinel program")]
+    AccountNotOwnedByProgram,
+}
+
+impl From<SentinelError> for ProgramError {
+    fn from(e: SentinelError) -> Self {
+        ProgramError::Custom(e as u32)
+    }
+}
+
+impl<T> DecodeError<T> for SentinelError {
+    fn type_of() -> &'static str {
+        "SentinelError"
+    }
+}
+
+impl PrintProgramError for SentinelError {
+    fn print<E>(&self)
+    where
+        E: 'static + std::error::Error + DecodeError<E> + PrintProgramError + num_traits::FromPrimitive,
+    {
+        msg!("{}", &self.to_string());
+    }
+}
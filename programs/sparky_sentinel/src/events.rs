@@ -0,0 +1,40 @@
+//! Structured monitoring events for off-chain indexers.
+//!
+//! Events are Borsh-serialized and emitted through the `log_data` syscall
+//! via `sol_log_data`, rather than as human-readable `msg!` strings, so
+//! off-chain consumers can cheaply parse binary event records from
+//! transaction logs instead of scraping text.
+
+use borsh::BorshSerialize;
+use solana_program::{log::sol_log_data, pubkey::Pubkey};
+
+/// Events emitted on sentinel state transitions.
+#[derive(Clone, Debug, PartialEq, BorshSerialize)]
+pub enum SentinelEvent {
+    /// A heartbeat was recorded.
+    Heartbeat {
+        /// Slot at which the heartbeat was recorded.
+        slot: u64,
+    },
+    /// The sentinel was tripped.
+    Trip {
+        /// Caller-supplied code identifying the reason for the trip.
+        code: u32,
+        /// Total number of trips recorded so far, including this one.
+        trip_count: u64,
+    },
+    /// The sentinel's authority changed.
+    AuthorityChanged {
+        /// The previous authority, or the default pubkey if none.
+        previous_authority: Pubkey,
+        /// The new authority.
+        new_authority: Pubkey,
+    },
+}
+
+/// Serializes `event` with Borsh and logs it via `sol_log_data` for
+/// off-chain indexers to pick up.
+pub fn emit_event(event: &SentinelEvent) {
+    let data = event.try_to_vec().expect("SentinelEvent serialization cannot fail");
+    sol_log_data(&[&data]);
+}
@@ -0,0 +1,20 @@
+//! Authority checks for privileged instructions.
+
+use solana_program::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{error::SentinelError, state::SentinelState};
+
+/// Verifies that `signer` is both a transaction signer and the sentinel's
+/// current authority, returning `SentinelError::Unauthorized` otherwise.
+pub fn assert_authority(
+    state: &SentinelState,
+    signer: &AccountInfo,
+) -> Result<(), ProgramError> {
+    if !signer.is_signer {
+        return Err(SentinelError::Unauthorized.into());
+    }
+    if *signer.key != state.authority {
+        return Err(SentinelError::Unauthorized.into());
+    }
+    Ok(())
+}
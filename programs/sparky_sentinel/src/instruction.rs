@@ -0,0 +1,59 @@
+//! Instruction types supported by the Sparky Sentinel program.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::program_error::ProgramError;
+
+use crate::error::SentinelError;
+
+/// Instructions supported by the Sentinel program.
+#[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+pub enum SentinelInstruction {
+    /// Initialize a new sentinel state account.
+    ///
+    /// Creates the sentinel state account via a CPI to the System Program,
+    /// so the account does not need to exist ahead of time. The account is
+    /// a PDA derived from `[SENTINEL_SEED_PREFIX, authority_account.key]`.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable, signer]` Payer funding the new account's rent.
+    /// 1. `[writable]` Sentinel state account (PDA), uninitialized.
+    /// 2. `[signer]` Authority that will own the sentinel.
+    /// 3. `[]` System program.
+    Initialize,
+
+    /// Register a guard under the sentinel's authority.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Sentinel state account.
+    /// 1. `[signer]` Authority.
+    RegisterGuard,
+
+    /// Record a heartbeat at the current slot.
+    ///
+    /// No signer is required and the caller is not checked against the
+    /// registered guards.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Sentinel state account.
+    Heartbeat,
+
+    /// Trip the sentinel, recording an incident.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` Sentinel state account.
+    /// 1. `[signer]` Authority.
+    Trip {
+        /// Caller-supplied code identifying the reason for the trip.
+        code: u32,
+    },
+}
+
+impl SentinelInstruction {
+    /// Unpacks a byte buffer into a `SentinelInstruction`.
+    ///
+    /// The first byte is the variant discriminant; the remainder is the
+    /// Borsh-serialized payload for that variant.
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        Self::try_from_slice(data).map_err(|_| SentinelError::InvalidInstructionData.into())
+    }
+}
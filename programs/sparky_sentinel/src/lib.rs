@@ -1,18 +1,27 @@
+pub mod error;
+pub mod events;
+pub mod guard;
+pub mod instruction;
+pub mod processor;
+pub mod state;
+
 use solana_program::{
-    account_info::AccountInfo,
-    entrypoint,
-    entrypoint::ProgramResult,
-    msg,
-    pubkey::Pubkey,
+    account_info::AccountInfo, entrypoint, entrypoint::ProgramResult,
+    program_error::PrintProgramError, pubkey::Pubkey,
 };
 
+use crate::{error::SentinelError, processor::Processor};
+
 entrypoint!(process_instruction);
 
 fn process_instruction(
     program_id: &Pubkey,
-    _accounts: &[AccountInfo],
-    _instruction_data: &[u8],
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
 ) -> ProgramResult {
-    msg!("Sparky Sentinel initialized from: {:?}", program_id);
+    if let Err(error) = Processor::process(program_id, accounts, instruction_data) {
+        error.print::<SentinelError>();
+        return Err(error);
+    }
     Ok(())
 }
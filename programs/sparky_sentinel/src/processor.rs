@@ -0,0 +1,198 @@
+//! Program state processor.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::{next_account_info, AccountInfo},
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke_signed,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction,
+    sysvar::{clock::Clock, rent::Rent, Sysvar},
+};
+
+use crate::{
+    error::SentinelError,
+    events::{emit_event, SentinelEvent},
+    guard::assert_authority,
+    instruction::SentinelInstruction,
+    state::SentinelState,
+};
+
+/// Seed prefix used to derive a sentinel state account's PDA, together with
+/// the authority's pubkey.
+pub const SENTINEL_SEED_PREFIX: &[u8] = b"sentinel";
+
+/// Dispatches instructions to their per-instruction handlers.
+pub struct Processor;
+
+impl Processor {
+    /// Processes a `SentinelInstruction` against the given accounts.
+    pub fn process(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        instruction_data: &[u8],
+    ) -> ProgramResult {
+        let instruction = SentinelInstruction::unpack(instruction_data)?;
+
+        match instruction {
+            SentinelInstruction::Initialize => {
+                msg!("SentinelInstruction: Initialize");
+                Self::process_initialize(program_id, accounts)
+            }
+            SentinelInstruction::RegisterGuard => {
+                msg!("SentinelInstruction: RegisterGuard");
+                Self::process_register_guard(program_id, accounts)
+            }
+            SentinelInstruction::Heartbeat => {
+                msg!("SentinelInstruction: Heartbeat");
+                Self::process_heartbeat(program_id, accounts)
+            }
+            SentinelInstruction::Trip { code } => {
+                msg!("SentinelInstruction: Trip");
+                Self::process_trip(program_id, accounts, code)
+            }
+        }
+    }
+
+    fn process_initialize(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let payer_account = next_account_info(accounts_iter)?;
+        let state_account = next_account_info(accounts_iter)?;
+        let authority_account = next_account_info(accounts_iter)?;
+        let system_program_account = next_account_info(accounts_iter)?;
+
+        if !authority_account.is_signer {
+            return Err(SentinelError::Unauthorized.into());
+        }
+
+        let (state_pda, bump_seed) = Pubkey::find_program_address(
+            &[SENTINEL_SEED_PREFIX, authority_account.key.as_ref()],
+            program_id,
+        );
+        if state_pda != *state_account.key {
+            return Err(ProgramError::InvalidSeeds);
+        }
+
+        if state_account.owner == program_id {
+            return Err(SentinelError::AlreadyInitialized.into());
+        }
+
+        let space = SentinelState::default().try_to_vec()?.len() as u64;
+        let rent = Rent::get()?;
+        let lamports = rent.minimum_balance(space as usize);
+
+        invoke_signed(
+            &system_instruction::create_account(
+                payer_account.key,
+                state_account.key,
+                lamports,
+                space,
+                program_id,
+            ),
+            &[
+                payer_account.clone(),
+                state_account.clone(),
+                system_program_account.clone(),
+            ],
+            &[&[
+                SENTINEL_SEED_PREFIX,
+                authority_account.key.as_ref(),
+                &[bump_seed],
+            ]],
+        )?;
+
+        let state = SentinelState {
+            is_initialized: true,
+            authority: *authority_account.key,
+            trip_count: 0,
+            last_heartbeat_slot: 0,
+            guard_count: 0,
+        };
+        state.serialize(&mut &mut state_account.data.borrow_mut()[..])?;
+
+        emit_event(&SentinelEvent::AuthorityChanged {
+            previous_authority: Pubkey::default(),
+            new_authority: state.authority,
+        });
+
+        msg!("Sentinel initialized");
+        Ok(())
+    }
+
+    fn process_register_guard(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let state_account = next_account_info(accounts_iter)?;
+        let authority_account = next_account_info(accounts_iter)?;
+
+        Self::check_owned_by_program(program_id, state_account)?;
+
+        let mut state = SentinelState::try_from_slice(&state_account.data.borrow())?;
+        assert_authority(&state, authority_account)?;
+
+        state.guard_count += 1;
+        state.serialize(&mut &mut state_account.data.borrow_mut()[..])?;
+
+        msg!(
+            "Guard registered by {:?}, total guards: {}",
+            authority_account.key,
+            state.guard_count
+        );
+        Ok(())
+    }
+
+    fn process_heartbeat(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let state_account = next_account_info(accounts_iter)?;
+
+        Self::check_owned_by_program(program_id, state_account)?;
+
+        let mut state = SentinelState::try_from_slice(&state_account.data.borrow())?;
+        if !state.is_initialized {
+            return Err(SentinelError::NotInitialized.into());
+        }
+        state.last_heartbeat_slot = Clock::get()?.slot;
+        state.serialize(&mut &mut state_account.data.borrow_mut()[..])?;
+
+        emit_event(&SentinelEvent::Heartbeat {
+            slot: state.last_heartbeat_slot,
+        });
+
+        msg!("Heartbeat recorded at slot {}", state.last_heartbeat_slot);
+        Ok(())
+    }
+
+    fn process_trip(program_id: &Pubkey, accounts: &[AccountInfo], code: u32) -> ProgramResult {
+        let accounts_iter = &mut accounts.iter();
+        let state_account = next_account_info(accounts_iter)?;
+        let authority_account = next_account_info(accounts_iter)?;
+
+        Self::check_owned_by_program(program_id, state_account)?;
+
+        let mut state = SentinelState::try_from_slice(&state_account.data.borrow())?;
+        if !state.is_initialized {
+            return Err(SentinelError::NotInitialized.into());
+        }
+        assert_authority(&state, authority_account)?;
+
+        state.trip_count += 1;
+        state.serialize(&mut &mut state_account.data.borrow_mut()[..])?;
+
+        emit_event(&SentinelEvent::Trip {
+            code,
+            trip_count: state.trip_count,
+        });
+
+        msg!("Sentinel tripped with code: {}", code);
+        Ok(())
+    }
+
+    /// Verifies that `account` is owned by this program before it is mutated.
+    fn check_owned_by_program(program_id: &Pubkey, account: &AccountInfo) -> ProgramResult {
+        if account.owner != program_id {
+            return Err(SentinelError::AccountNotOwnedByProgram.into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+//! State transition types for the Sentinel program.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Sentinel account state, persisted in a program-owned account.
+#[derive(Clone, Debug, Default, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct SentinelState {
+    /// Whether this sentinel account has been initialized.
+    pub is_initialized: bool,
+    /// The authority allowed to perform privileged operations.
+    pub authority: Pubkey,
+    /// Number of times the sentinel has been tripped.
+    pub trip_count: u64,
+    /// Slot of the last recorded heartbeat.
+    pub last_heartbeat_slot: u64,
+    /// Number of guards registered with this sentinel.
+    pub guard_count: u64,
+}